@@ -0,0 +1,43 @@
+///! Display attributes (foreground/background color and text modifiers)
+///! applied to a cell.
+
+/// Bold text.
+pub const MODIFIER_BOLD: u32 = 0b0000_0001;
+/// Underlined text.
+pub const MODIFIER_UNDERLINE: u32 = 0b0000_0010;
+/// Reversed (swapped fg/bg) text.
+pub const MODIFIER_REVERSE: u32 = 0b0000_0100;
+
+/// Attribute of a single cell: its foreground/background color and
+/// modifiers such as bold or underline.
+///
+/// `fg`/`bg` of `None` mean "transparent": merging this attribute onto an
+/// existing one keeps the existing color instead of overwriting it with a
+/// default. `add_modifier`/`sub_modifier` record modifiers to turn on/off
+/// respectively, so that [`Attr::merge`] can layer one attribute on top of
+/// another without losing either side's intent. A fuller `Color` enum is
+/// intentionally omitted here; this crate currently only needs a value that
+/// can be defaulted and copied around.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Attr {
+    pub fg: Option<u32>,
+    pub bg: Option<u32>,
+    pub add_modifier: u32,
+    pub sub_modifier: u32,
+}
+
+impl Attr {
+    /// Layer `other` on top of `self`, treating `other`'s `None` fg/bg as
+    /// transparent (i.e. "keep whatever `self` had").
+    pub fn merge(mut self, other: Attr) -> Attr {
+        self.fg = other.fg.or(self.fg);
+        self.bg = other.bg.or(self.bg);
+
+        self.add_modifier &= !other.sub_modifier;
+        self.add_modifier |= other.add_modifier;
+        self.sub_modifier &= !other.add_modifier;
+        self.sub_modifier |= other.sub_modifier;
+
+        self
+    }
+}
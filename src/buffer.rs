@@ -0,0 +1,130 @@
+///! A double-buffered `Canvas` that can diff itself against the previous
+///! frame so a rendering backend only has to repaint the cells that
+///! actually changed.
+use crate::canvas::{Canvas, Result};
+use crate::cell::Cell;
+use unicode_width::UnicodeWidthStr;
+
+/// A `Canvas` backed by an in-memory `width * height` buffer of cells,
+/// indexed `y * width + x`.
+///
+/// Keep two of these around (current & previous frame) and call
+/// [`BufferCanvas::diff`] to get just the cells that changed, instead of
+/// repainting the whole screen every frame.
+pub struct BufferCanvas {
+    width: usize,
+    height: usize,
+    cells: Vec<Cell>,
+    cursor_row: usize,
+    cursor_col: usize,
+    cursor_visible: bool,
+}
+
+impl BufferCanvas {
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![Cell::empty(); width * height],
+            cursor_row: 0,
+            cursor_col: 0,
+            cursor_visible: true,
+        }
+    }
+
+    fn index(&self, row: usize, col: usize) -> usize {
+        row * self.width + col
+    }
+
+    fn cell(&self, row: usize, col: usize) -> Option<&Cell> {
+        if row >= self.height || col >= self.width {
+            return None;
+        }
+        self.cells.get(self.index(row, col))
+    }
+
+    /// Reset the buffer back to its initial blank state, e.g. before
+    /// starting a fresh frame.
+    pub fn reset(&mut self) {
+        for cell in self.cells.iter_mut() {
+            *cell = Cell::empty();
+        }
+        self.cursor_row = 0;
+        self.cursor_col = 0;
+        self.cursor_visible = true;
+    }
+
+    /// Swap the contents of two buffers, e.g. to recycle the previous
+    /// frame's buffer as the next frame's scratch space.
+    pub fn swap(&mut self, other: &mut BufferCanvas) {
+        std::mem::swap(&mut self.width, &mut other.width);
+        std::mem::swap(&mut self.height, &mut other.height);
+        std::mem::swap(&mut self.cells, &mut other.cells);
+        std::mem::swap(&mut self.cursor_row, &mut other.cursor_row);
+        std::mem::swap(&mut self.cursor_col, &mut other.cursor_col);
+        std::mem::swap(&mut self.cursor_visible, &mut other.cursor_visible);
+    }
+
+    /// Compute the cells that differ between `self` and `prev`, skipping
+    /// continuation cells of wide glyphs since a backend should never draw
+    /// those on their own.
+    pub fn diff(&self, prev: &BufferCanvas) -> Vec<(usize, usize, Cell)> {
+        let mut changes = Vec::new();
+        for row in 0..self.height {
+            for col in 0..self.width {
+                let cell = &self.cells[self.index(row, col)];
+                if cell.is_continuation() {
+                    continue;
+                }
+                if prev.cell(row, col) != Some(cell) {
+                    changes.push((row, col, cell.clone()));
+                }
+            }
+        }
+        changes
+    }
+}
+
+impl Canvas for BufferCanvas {
+    fn size(&self) -> Result<(usize, usize)> {
+        Ok((self.width, self.height))
+    }
+
+    fn clear(&mut self) -> Result<()> {
+        self.reset();
+        Ok(())
+    }
+
+    fn put_cell(&mut self, row: usize, col: usize, cell: Cell) -> Result<usize> {
+        if row >= self.height || col >= self.width {
+            return Err(format!("({}, {}) out of box", row, col).into());
+        }
+
+        let width = if cell.is_continuation() {
+            1
+        } else {
+            cell.symbol.width().max(1)
+        };
+        let index = self.index(row, col);
+        self.cells[index] = cell;
+        Ok(width)
+    }
+
+    fn get_cell(&self, row: usize, col: usize) -> Result<Cell> {
+        self.cell(row, col)
+            .cloned()
+            .ok_or_else(|| format!("({}, {}) out of box", row, col).into())
+    }
+
+    fn set_cursor(&mut self, row: usize, col: usize) -> Result<()> {
+        self.cursor_row = row;
+        self.cursor_col = col;
+        self.cursor_visible = true;
+        Ok(())
+    }
+
+    fn show_cursor(&mut self, show: bool) -> Result<()> {
+        self.cursor_visible = show;
+        Ok(())
+    }
+}
@@ -2,7 +2,8 @@
 use crate::attr::Attr;
 use crate::cell::Cell;
 use std::error::Error;
-use unicode_width::UnicodeWidthChar;
+use unicode_segmentation::UnicodeSegmentation;
+use unicode_width::UnicodeWidthStr;
 
 pub type Result<T> = std::result::Result<T, Box<dyn Error>>;
 
@@ -17,15 +18,59 @@ pub trait Canvas {
     /// return the width of the character/cell
     fn put_cell(&mut self, row: usize, col: usize, cell: Cell) -> Result<usize>;
 
+    /// read back the cell currently at position `(row, col)`
+    fn get_cell(&self, row: usize, col: usize) -> Result<Cell>;
+
+    /// like put_cell, except transparent fg/bg in `cell`'s attribute keep
+    /// whatever color is already at `(row, col)`, add-modifiers are unioned
+    /// in, and remove-modifiers are cleared, instead of unconditionally
+    /// overwriting the existing cell
+    /// return the width of the character/cell
+    fn put_cell_merge(&mut self, row: usize, col: usize, cell: Cell) -> Result<usize> {
+        let existing = self.get_cell(row, col)?;
+        let merged = Cell {
+            symbol: cell.symbol,
+            attr: existing.attr.merge(cell.attr),
+        };
+        self.put_cell(row, col, merged)
+    }
+
     /// just like put_cell, except it accept (char & attr)
     /// return the width of the character/cell
     fn put_ch_with_attr(&mut self, row: usize, col: usize, ch: char, attr: Attr) -> Result<usize> {
-        self.put_cell(row, col, Cell {ch, attr})
+        self.put_cell(row, col, Cell::from_char(ch, attr))
+    }
+
+    /// just like put_cell, except it accepts a whole grapheme cluster (`&str`)
+    /// instead of a single `char`
+    /// return the width of the cluster/cell
+    fn put_symbol_with_attr(
+        &mut self,
+        row: usize,
+        col: usize,
+        symbol: &str,
+        attr: Attr,
+    ) -> Result<usize> {
+        let width = symbol.width().max(1);
+        self.put_cell(
+            row,
+            col,
+            Cell {
+                symbol: symbol.to_string(),
+                attr,
+            },
+        )?;
+        for i in 1..width {
+            self.put_cell(row, col + i, Cell::continuation())?;
+        }
+        Ok(width)
     }
 
     /// print `content` starting with position `(row, col)` with `attr`
     /// - canvas should NOT wrap to y+1 if the content is too long
     /// - canvas should handle wide characters
+    /// - `content` is segmented into grapheme clusters so that combining
+    ///   marks, ZWJ emoji sequences and flags are kept whole in a single cell
     /// return the printed width of the content
     fn print_with_attr(
         &mut self,
@@ -34,16 +79,9 @@ pub trait Canvas {
         content: &str,
         attr: Attr,
     ) -> Result<usize> {
-        let mut cell = Cell {
-            attr,
-            ..Cell::default()
-        };
-
         let mut width = 0;
-        for ch in content.chars() {
-            cell.ch = ch;
-            self.put_cell(row, col + width, cell)?;
-            width += ch.width().unwrap_or(2);
+        for cluster in content.graphemes(true) {
+            width += self.put_symbol_with_attr(row, col + width, cluster, attr)?;
         }
         Ok(width)
     }
@@ -53,6 +91,32 @@ pub trait Canvas {
         self.print_with_attr(row, col, content, Attr::default())
     }
 
+    /// clear the canvas, filling every cell with `blank` instead of the
+    /// default empty cell, e.g. to lay down a colored background
+    fn clear_with(&mut self, blank: Cell) -> Result<()> {
+        let (width, height) = self.size()?;
+        self.fill_rect(0, 0, width, height, blank)
+    }
+
+    /// fill the sub-rectangle starting at `(top, left)` of size
+    /// `width x height` with `blank`, without looping over `put_cell`
+    /// yourself
+    fn fill_rect(
+        &mut self,
+        top: usize,
+        left: usize,
+        width: usize,
+        height: usize,
+        blank: Cell,
+    ) -> Result<()> {
+        for row in top..(top + height) {
+            for col in left..(left + width) {
+                self.put_cell(row, col, blank.clone())?;
+            }
+        }
+        Ok(())
+    }
+
     /// move cursor position (row, col) and show cursor
     fn set_cursor(&mut self, row: usize, col: usize) -> Result<()>;
 
@@ -95,13 +159,7 @@ impl<'a> Canvas for BoundedCanvas<'a> {
     }
 
     fn clear(&mut self) -> Result<()> {
-        for row in self.top..(self.top + self.height) {
-            for col in self.left..(self.left + self.width) {
-                let _ = self.put_cell(row, col, Cell::empty());
-            }
-        }
-
-        Ok(())
+        self.clear_with(Cell::empty())
     }
 
     fn put_cell(&mut self, row: usize, col: usize, cell: Cell) -> Result<usize> {
@@ -112,6 +170,14 @@ impl<'a> Canvas for BoundedCanvas<'a> {
         self.canvas.put_cell(row + self.top, col + self.left, cell)
     }
 
+    fn get_cell(&self, row: usize, col: usize) -> Result<Cell> {
+        if row >= self.height || col >= self.width {
+            return Err(format!("({}, {}) out of box", row, col).into());
+        }
+
+        self.canvas.get_cell(row + self.top, col + self.left)
+    }
+
     fn set_cursor(&mut self, row: usize, col: usize) -> Result<()> {
         if row >= self.height || col >= self.width {
             return Err(format!("({}, {}) out of box", row, col).into());
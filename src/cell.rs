@@ -0,0 +1,57 @@
+///! A cell is the smallest unit that a canvas/terminal can draw: a single
+///! grapheme cluster together with its display attribute.
+use crate::attr::Attr;
+
+/// A single cell in the terminal buffer.
+///
+/// `symbol` holds a whole grapheme cluster (e.g. a ZWJ emoji sequence or a
+/// flag made of two regional indicators) rather than a single `char`, so
+/// that such clusters can be kept together and rendered as one visual unit.
+/// A cell whose `symbol` is empty is a *continuation* cell: it marks the
+/// trailing column(s) of a wide cluster drawn in a preceding cell and
+/// should never be drawn on its own.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cell {
+    pub symbol: String,
+    pub attr: Attr,
+}
+
+impl Cell {
+    /// An empty, blank cell: a single space with default attributes.
+    pub fn empty() -> Self {
+        Cell {
+            symbol: " ".to_string(),
+            attr: Attr::default(),
+        }
+    }
+
+    /// A continuation cell marking the tail column(s) of a wide grapheme
+    /// cluster drawn in the cell to its left.
+    pub fn continuation() -> Self {
+        Cell {
+            symbol: String::new(),
+            attr: Attr::default(),
+        }
+    }
+
+    /// Whether this cell is a continuation of a wide cluster drawn earlier
+    /// in the row.
+    pub fn is_continuation(&self) -> bool {
+        self.symbol.is_empty()
+    }
+
+    /// Build a cell from a single `char`, kept for callers that don't need
+    /// full grapheme-cluster handling.
+    pub fn from_char(ch: char, attr: Attr) -> Self {
+        Cell {
+            symbol: ch.to_string(),
+            attr,
+        }
+    }
+}
+
+impl Default for Cell {
+    fn default() -> Self {
+        Cell::empty()
+    }
+}